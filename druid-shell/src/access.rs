@@ -0,0 +1,99 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Glue between `accesskit_winit` and the druid event loop.
+//!
+//! This is the one place in the shell that knows about AccessKit: it owns
+//! the `accesskit_winit::Adapter`, asks the app root to build a fresh
+//! `TreeUpdate` on request (via `druid::WindowRoot::build_access_tree`), and
+//! turns incoming `ActionRequest`s into the same `Event`s the rest of the
+//! shell already dispatches.
+//!
+//! AccessKit's action handler can be invoked from a background thread, so
+//! it can't dispatch straight into the (single-threaded) druid event pass.
+//! Instead it pushes onto `pending_actions`, and the window's run loop calls
+//! [`AccessAdapter::drain_actions`] once per pass, alongside the other event
+//! sources it already polls, feeding each `(WidgetId, Event)` pair to
+//! `druid::WindowRoot::do_targeted_event` rather than `do_event`, so the
+//! action only reaches the widget it actually named.
+//!
+//! This file assumes a `WindowRoot` the run loop already owns and an
+//! `accesskit_winit`/`accesskit` surface matching the shapes used below;
+//! neither the winit event loop itself nor a vendored `accesskit` crate are
+//! part of this tree. Feeding `drain_actions` into a live `WindowRoot` each
+//! pass is done by `run_loop::WindowRunner`; constructing the `Adapter` for
+//! a live winit window is left to the caller.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use accesskit::{Action, ActionRequest, TreeUpdate};
+use accesskit_winit::Adapter;
+use winit::window::Window as WinitWindow;
+
+use druid::{Event, WidgetId};
+
+/// Owns the AccessKit adapter for a single window.
+pub struct AccessAdapter {
+    adapter: Adapter,
+    pending_actions: Arc<Mutex<VecDeque<ActionRequest>>>,
+}
+
+impl AccessAdapter {
+    /// Create the adapter for `window`. `build_tree` is called lazily by
+    /// AccessKit the first time the platform's assistive tech asks for the
+    /// tree; later changes are pushed with `request_update` below, which
+    /// takes an already-built `TreeUpdate` and does not call `build_tree`.
+    pub fn new(window: &WinitWindow, build_tree: impl FnMut() -> TreeUpdate + Send + 'static) -> Self {
+        let pending_actions = Arc::new(Mutex::new(VecDeque::new()));
+        let handler_queue = Arc::clone(&pending_actions);
+        AccessAdapter {
+            adapter: Adapter::with_action_handler(
+                window,
+                build_tree,
+                Box::new(move |request| handler_queue.lock().unwrap().push_back(request)),
+            ),
+            pending_actions,
+        }
+    }
+
+    /// Push `update` to the platform, if its assistive tech is active.
+    pub fn request_update(&self, update: TreeUpdate) {
+        self.adapter.update_if_active(|| update);
+    }
+
+    /// Translate an incoming AccessKit action into a druid `Event` targeted
+    /// at the widget the node id was minted for, and return it for the
+    /// window to dispatch through the normal `event` pass.
+    pub fn translate_action(request: ActionRequest) -> Option<(WidgetId, Event)> {
+        let widget_id = WidgetId::from_access_id(request.target)?;
+        let event = match request.action {
+            Action::Focus => Event::AccessFocus,
+            Action::Default => Event::AccessClick,
+            Action::SetValue => Event::AccessSetValue(request.data?.as_value()?),
+            _ => return None,
+        };
+        Some((widget_id, event))
+    }
+
+    /// Drain every `ActionRequest` queued since the last call, translating
+    /// each via [`translate_action`](Self::translate_action) into the
+    /// `(WidgetId, Event)` pair the window should dispatch through its
+    /// normal `event` pass. Requests `translate_action` doesn't recognize
+    /// are silently dropped, same as an unrecognized `Action` already was.
+    pub fn drain_actions(&self) -> Vec<(WidgetId, Event)> {
+        let mut pending = self.pending_actions.lock().unwrap();
+        pending.drain(..).filter_map(Self::translate_action).collect()
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Drives a single window's `WindowRoot` from the shell, tying together the
+//! pieces `access.rs` describes but doesn't itself own: a live window, the
+//! application data, and the `AccessAdapter` that keeps assistive tech in
+//! sync.
+//!
+//! A `WindowRunner` is the layer one level up from `access.rs` — the thing
+//! an app would actually build and hand its window's events to. It feeds
+//! `drain_actions` into its `WindowRoot`; it does not construct the
+//! `AccessAdapter`, which is passed in ready-made.
+
+use druid::{Env, Event, EventCtx, Widget, WindowHandle, WindowRoot};
+
+use crate::access::AccessAdapter;
+
+/// Owns everything one window needs to keep running: its widget tree, its
+/// application data and environment, and the `AccessAdapter` that mirrors
+/// the tree to the platform's accessibility API.
+pub struct WindowRunner<T, W> {
+    root: WindowRoot<T, W>,
+    access: AccessAdapter,
+    data: T,
+    env: Env,
+}
+
+impl<T, W: Widget<T>> WindowRunner<T, W> {
+    /// Create a runner for `root`, starting from `data` and `env`, reporting
+    /// to assistive tech through `access`.
+    pub fn new(root: W, data: T, env: Env, access: AccessAdapter) -> Self {
+        WindowRunner {
+            root: WindowRoot::new(root),
+            access,
+            data,
+            env,
+        }
+    }
+
+    /// Dispatch one untargeted event — a window-level notification, a key
+    /// press, anything not aimed at one specific widget — to the whole
+    /// tree.
+    pub fn handle_event(&mut self, window: &WindowHandle, event: &Event) {
+        let mut ctx = EventCtx::new(window, self.root.root_id());
+        self.root.do_event(&mut ctx, event, &mut self.data, &self.env);
+    }
+
+    /// Drain every AccessKit action queued since the last call, and
+    /// dispatch each one at the exact widget it named. Call this once per
+    /// pass, alongside whatever other event sources the run loop already
+    /// polls — the same cadence `access.rs` already documents for
+    /// `AccessAdapter::drain_actions`.
+    pub fn pump_access_actions(&mut self, window: &WindowHandle) {
+        for (target, event) in self.access.drain_actions() {
+            let mut ctx = EventCtx::new(window, self.root.root_id());
+            self.root
+                .do_targeted_event(&mut ctx, target, &event, &mut self.data, &self.env);
+        }
+    }
+
+    /// Ask the AccessKit adapter to push a freshly built tree, reflecting
+    /// whatever `handle_event`/`pump_access_actions` calls changed since the
+    /// last one.
+    pub fn refresh_access_tree(&mut self, window: &WindowHandle) {
+        let update = self.root.build_access_tree(window, &self.data, &self.env);
+        self.access.request_update(update);
+    }
+}
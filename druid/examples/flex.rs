@@ -14,14 +14,15 @@
 
 //! Demonstrates alignment of children in the flex container.
 
+use accesskit::NodeId;
 use druid::widget::{
-    Button, Checkbox, CrossAxisAlignment, Flex, Label, MainAxisAlignment, ProgressBar, RadioGroup,
-    SizedBox, Slider, Stepper, Switch, TextBox, WidgetExt,
+    Button, Checkbox, CrossAxisAlignment, Flex, FocusTraversal, Label, MainAxisAlignment,
+    Operation, ProgressBar, RadioGroup, SizedBox, Slider, Stepper, Switch, TextBox, WidgetExt,
 };
 use druid::{
-    AppLauncher, BoxConstraints, Color, Data, Env, Event, EventCtx, LayoutCtx, Lens, LensExt,
-    LifeCycle, LifeCycleCtx, LocalizedString, PaintCtx, PlatformError, Size, UnitPoint, UpdateCtx,
-    Widget, WidgetId, WindowDesc,
+    AccessCtx, AppLauncher, BoxConstraints, Color, Data, Env, Event, EventCtx, KbKey, LayoutCtx,
+    Lens, LensExt, LifeCycle, LifeCycleCtx, LocalizedString, OperationCtx, PaintCtx, PlatformError,
+    Size, UnitPoint, UpdateCtx, Widget, WidgetId, WindowDesc,
 };
 
 const DEFAULT_SPACER_SIZE: f64 = 8.;
@@ -84,7 +85,17 @@ impl Rebuilder {
 
 impl Widget<AppState> for Rebuilder {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut AppState, env: &Env) {
-        self.inner.event(ctx, event, data, env)
+        match event {
+            Event::KeyDown(key) if key.key == KbKey::Tab && !key.mods.shift() => {
+                ctx.apply_operation(FocusTraversal::focus_next());
+                ctx.set_handled();
+            }
+            Event::KeyDown(key) if key.key == KbKey::Tab && key.mods.shift() => {
+                ctx.apply_operation(FocusTraversal::focus_previous());
+                ctx.set_handled();
+            }
+            _ => self.inner.event(ctx, event, data, env),
+        }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &AppState, env: &Env) {
@@ -117,6 +128,17 @@ impl Widget<AppState> for Rebuilder {
         self.inner.paint(paint_ctx, data, env)
     }
 
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &AppState, env: &Env) -> Vec<NodeId> {
+        // `Rebuilder` has no semantics of its own; it's a transparent shell
+        // around whatever `build_widget` produced, so the accessibility
+        // tree should skip straight to that child.
+        self.inner.accessibility(ctx, data, env)
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        self.inner.apply_operation(ctx, op)
+    }
+
     fn id(&self) -> Option<WidgetId> {
         self.inner.id()
     }
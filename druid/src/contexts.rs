@@ -0,0 +1,279 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The context types that widget methods are passed.
+
+use accesskit::{CheckedState, Node, NodeId, Role, TreeUpdate};
+
+use crate::widget::Operation;
+use crate::{Rect, WidgetId, WindowHandle};
+
+/// A context passed to most widget methods, that allows the widget to submit
+/// paint invalidations and query basic information about the environment.
+pub struct EventCtx<'a, 'b> {
+    pub(crate) window: &'a WindowHandle,
+    pub(crate) widget_id: WidgetId,
+    pub(crate) target: Option<WidgetId>,
+    pub(crate) is_handled: bool,
+    pub(crate) is_root: bool,
+    pub(crate) request_focus: Option<WidgetId>,
+    pub(crate) pending_operation: Option<Box<dyn Operation>>,
+    pub(crate) _marker: std::marker::PhantomData<&'b ()>,
+}
+
+impl<'a, 'b> EventCtx<'a, 'b> {
+    /// Start a fresh, untargeted `EventCtx` for one pass over `window`'s
+    /// tree, from the root.
+    ///
+    /// `widget_id` is overwritten before any widget actually sees it —
+    /// `WidgetPod::event` swaps in its own id immediately on the way down —
+    /// so callers that don't have a more meaningful one at hand can pass
+    /// the tree's root id (see `WindowRoot::root_id`).
+    pub fn new(window: &'a WindowHandle, widget_id: WidgetId) -> Self {
+        EventCtx {
+            window,
+            widget_id,
+            target: None,
+            is_handled: false,
+            is_root: true,
+            request_focus: None,
+            pending_operation: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The `WidgetId` of the widget currently being visited.
+    pub fn widget_id(&self) -> WidgetId {
+        self.widget_id
+    }
+
+    /// Whether the widget currently being visited is the one `target`
+    /// names, or whether this event has no particular target at all (e.g.
+    /// a window-level notification that's meant to reach every widget).
+    ///
+    /// An event translated from an AccessKit `ActionRequest` (see
+    /// [`WindowRoot::do_targeted_event`](crate::WindowRoot::do_targeted_event))
+    /// is always targeted, since the action names the exact node it was
+    /// invoked on: a widget whose `event` reacts to `Event::AccessClick` and
+    /// the like should check this before acting, or it'll also fire for
+    /// every sibling of the same type an untargeted broadcast happens to
+    /// reach.
+    pub fn is_target(&self) -> bool {
+        self.target.map_or(true, |target| target == self.widget_id)
+    }
+
+    /// Request that `id` become the focused widget.
+    pub fn request_focus(&mut self, id: WidgetId) {
+        self.request_focus = Some(id);
+    }
+
+    /// Mark the current event as handled, so it isn't passed on to other
+    /// widgets.
+    pub fn set_handled(&mut self) {
+        self.is_handled = true;
+    }
+
+    /// Schedule `op` to be run over the whole widget tree, from the root,
+    /// once the current event has finished dispatching.
+    ///
+    /// This is the entry point for the tree-wide query/mutation mechanism
+    /// described on [`Operation`]: the window walks the tree from its root
+    /// widget, calling `apply_operation` on each `WidgetPod` in turn, and
+    /// each widget along the way reports itself to `op` via its hooks.
+    ///
+    /// [`Operation`]: ../widget/trait.Operation.html
+    pub fn apply_operation(&mut self, op: impl Operation + 'static) {
+        self.pending_operation = Some(Box::new(op));
+    }
+}
+
+/// A context provided to the [`lifecycle`] method on widgets.
+///
+/// [`lifecycle`]: trait.Widget.html#tymethod.lifecycle
+pub struct LifeCycleCtx<'a> {
+    pub(crate) window: &'a WindowHandle,
+    pub(crate) widget_id: WidgetId,
+}
+
+/// A context provided to the [`update`] method on widgets.
+///
+/// [`update`]: trait.Widget.html#tymethod.update
+pub struct UpdateCtx<'a> {
+    pub(crate) window: &'a WindowHandle,
+    pub(crate) widget_id: WidgetId,
+    pub(crate) children_changed: bool,
+}
+
+impl<'a> UpdateCtx<'a> {
+    /// Indicate that the children of this widget have changed, requiring a
+    /// `lifecycle` pass before the next layout.
+    pub fn children_changed(&mut self) {
+        self.children_changed = true;
+    }
+}
+
+/// A context provided to the [`layout`] method on widgets.
+///
+/// [`layout`]: trait.Widget.html#tymethod.layout
+pub struct LayoutCtx<'a> {
+    pub(crate) window: &'a WindowHandle,
+    pub(crate) widget_id: WidgetId,
+}
+
+/// A context passed to the [`paint`] method on widgets.
+///
+/// [`paint`]: trait.Widget.html#tymethod.paint
+pub struct PaintCtx<'a> {
+    pub(crate) window: &'a WindowHandle,
+    pub(crate) widget_id: WidgetId,
+}
+
+/// A context provided to the [`accessibility`] method on widgets, used to
+/// push nodes into the platform's accessibility tree.
+///
+/// A single `TreeUpdate` is threaded through an entire tree walk, in the same
+/// recursive fashion as `layout`, with each `WidgetPod` wrapping it in a
+/// fresh `AccessCtx` carrying its own id and bounds: a container calls `accessibility` on each
+/// of its children, then folds the [`NodeId`]s they return into its own
+/// node's children list (via [`AccessNode::with_children`]) before pushing
+/// itself, so the tree accesskit sees has the same shape as the one `layout`
+/// walks — a parent's `Node` lists its children, rather than each child
+/// pointing back up at a parent.
+///
+/// [`accessibility`]: trait.Widget.html#method.accessibility
+pub struct AccessCtx<'a> {
+    pub(crate) window: &'a WindowHandle,
+    pub(crate) widget_id: WidgetId,
+    pub(crate) bounds: Rect,
+    pub(crate) update: &'a mut TreeUpdate,
+}
+
+impl<'a> AccessCtx<'a> {
+    /// The `WidgetId` of the widget currently being visited.
+    pub fn widget_id(&self) -> WidgetId {
+        self.widget_id
+    }
+
+    /// The bounding rect, in its parent's coordinate space, that the last
+    /// `layout` pass placed the widget currently being visited at (its
+    /// `WidgetPod::layout_rect`).
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Push `node` into the tree for the widget currently being visited,
+    /// returning its id so a container can list it as one of its own
+    /// children via [`AccessNode::with_children`].
+    ///
+    /// `node`'s bounds are filled in from [`AccessCtx::bounds`], so callers
+    /// don't need to (and can't accidentally forget to) pass them along
+    /// separately.
+    pub fn push_node(&mut self, node: AccessNode) -> NodeId {
+        let id = self.widget_id.to_access_id();
+        let mut node = node.0;
+        node.set_bounds(self.bounds.into());
+        self.update.nodes.push((id, node));
+        id
+    }
+}
+
+/// A single accessibility node under construction, started from
+/// [`AccessNode::new`] and finished with [`AccessCtx::push_node`].
+///
+/// This exists because a leaf's semantics are almost never just a bare
+/// `Role`: a slider needs its value and range, a checkbox needs its checked
+/// state, and every control needs a label assistive tech can read. Bounds
+/// are the one field `AccessCtx::push_node` fills in itself, since it's
+/// always the pushing widget's own `layout` rect and there's no reason to
+/// make every call site repeat it.
+pub struct AccessNode(Node);
+
+impl AccessNode {
+    /// Start a node with the given `role` and no other properties set.
+    pub fn new(role: Role) -> Self {
+        AccessNode(Node::new(role))
+    }
+
+    /// Set the accessible name (the label assistive tech reads), returning
+    /// `self`.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.0.set_name(label.into());
+        self
+    }
+
+    /// Set the current numeric value, returning `self`. For controls like
+    /// [`Slider`](crate::widget::Slider) and [`Stepper`](crate::widget::Stepper).
+    pub fn with_numeric_value(mut self, value: f64) -> Self {
+        self.0.set_numeric_value(value);
+        self
+    }
+
+    /// Set the current text value, returning `self`. For controls like
+    /// [`TextBox`](crate::widget::TextBox).
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.0.set_value(value.into());
+        self
+    }
+
+    /// Set the `min..=max` numeric range, returning `self`.
+    pub fn with_numeric_range(mut self, min: f64, max: f64) -> Self {
+        self.0.set_min_numeric_value(min);
+        self.0.set_max_numeric_value(max);
+        self
+    }
+
+    /// Set the toggled/checked state, returning `self`. For controls like
+    /// [`Checkbox`](crate::widget::Checkbox) and [`Switch`](crate::widget::Switch).
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.0.set_checked_state(if checked {
+            CheckedState::True
+        } else {
+            CheckedState::False
+        });
+        self
+    }
+
+    /// Set the ids of this node's children, in tree order, returning `self`.
+    /// Used by containers to report the nodes their own `accessibility`
+    /// call returned.
+    pub fn with_children(mut self, children: Vec<NodeId>) -> Self {
+        self.0.set_children(children);
+        self
+    }
+}
+
+/// A context provided to the [`apply_operation`] method on widgets.
+///
+/// Threaded through an [`Operation`] tree walk the same way `AccessCtx` is
+/// threaded through an accessibility pass.
+///
+/// [`apply_operation`]: trait.Widget.html#method.apply_operation
+/// [`Operation`]: ../widget/trait.Operation.html
+pub struct OperationCtx {
+    pub(crate) widget_id: WidgetId,
+    pub(crate) focused_widget: Option<WidgetId>,
+}
+
+impl OperationCtx {
+    /// The `WidgetId` of the widget currently being visited.
+    pub fn widget_id(&self) -> WidgetId {
+        self.widget_id
+    }
+
+    /// Whether the widget currently being visited is the one that has
+    /// focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused_widget == Some(self.widget_id)
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The base widget type that all other widgets are wrapped in, holding the
+//! per-widget state used by layout, event dispatch and accessibility.
+
+use accesskit::NodeId;
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx,
+    OperationCtx, PaintCtx, Point, Rect, Size, UpdateCtx, Widget, WidgetId,
+};
+
+/// A container for one widget in the tree.
+///
+/// `WidgetPod` is the unit that the tree-walking passes (`event`,
+/// `lifecycle`, `update`, `layout`, and `accessibility`) recurse through; it
+/// owns the per-widget bookkeeping (id, last computed layout rect, and so
+/// on) that the inner widget itself doesn't need to know about.
+pub struct WidgetPod<T, W> {
+    state: WidgetState,
+    inner: W,
+    _marker: std::marker::PhantomData<T>,
+}
+
+struct WidgetState {
+    id: WidgetId,
+    layout_rect: Rect,
+}
+
+impl<T, W: Widget<T>> WidgetPod<T, W> {
+    /// Create a new `WidgetPod` wrapping `inner`.
+    pub fn new(inner: W) -> Self {
+        let id = inner.id().unwrap_or_else(WidgetId::next);
+        WidgetPod {
+            state: WidgetState {
+                id,
+                layout_rect: Rect::ZERO,
+            },
+            inner,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The identity of the wrapped widget.
+    pub fn id(&self) -> WidgetId {
+        self.state.id
+    }
+
+    /// The rect this widget was placed at during the last `layout` pass,
+    /// in its parent's coordinate space.
+    pub fn layout_rect(&self) -> Rect {
+        self.state.layout_rect
+    }
+
+    /// Set the origin of this widget, in its parent's coordinate space, as
+    /// determined by the parent's layout algorithm.
+    pub fn set_origin(&mut self, origin: Point) {
+        self.state.layout_rect = self.state.layout_rect.with_origin(origin);
+    }
+
+    /// Box the inner widget, erasing its concrete type.
+    pub fn boxed(self) -> WidgetPod<T, Box<dyn Widget<T>>>
+    where
+        W: 'static,
+    {
+        WidgetPod {
+            state: self.state,
+            inner: Box::new(self.inner),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Run the event pass for this widget.
+    ///
+    /// This swaps in the pod's own `WidgetId` for the duration of the call
+    /// (restoring the caller's afterward), so that `EventCtx::widget_id` and
+    /// `EventCtx::is_target` are always correct for the widget currently
+    /// being visited, the same way `accessibility` and `apply_operation`
+    /// keep their contexts' id current. Unlike those two, this mutates
+    /// `ctx` in place rather than building a child context, since
+    /// `is_handled`/`request_focus`/`pending_operation` need to keep
+    /// accumulating across the whole pass rather than being scoped to one
+    /// widget.
+    pub fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let parent_id = ctx.widget_id;
+        ctx.widget_id = self.state.id;
+        self.inner.event(ctx, event, data, env);
+        ctx.widget_id = parent_id;
+    }
+
+    pub fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    pub fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    pub fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.inner.layout(ctx, bc, data, env);
+        self.state.layout_rect = Rect::from_origin_size(self.state.layout_rect.origin(), size);
+        size
+    }
+
+    pub fn paint(&mut self, paint_ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(paint_ctx, data, env)
+    }
+
+    /// Run the accessibility pass for this widget and its descendants.
+    ///
+    /// This swaps in the pod's own `WidgetId` and last-computed `layout_rect`
+    /// before delegating to the inner widget, so that `AccessCtx::widget_id`
+    /// and `AccessCtx::bounds` always reflect the widget currently being
+    /// visited rather than its parent. The inner widget is responsible for
+    /// pushing its own node(s) (if any) and, for containers, recursing into
+    /// their children's `WidgetPod::accessibility`; either way, the ids it
+    /// pushed are returned so a container can report them as its own
+    /// children.
+    pub fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) -> Vec<NodeId> {
+        let mut child_ctx = AccessCtx {
+            window: ctx.window,
+            widget_id: self.state.id,
+            bounds: self.state.layout_rect,
+            update: ctx.update,
+        };
+        self.inner.accessibility(&mut child_ctx, data, env)
+    }
+
+    /// Run `op` over this widget and its descendants.
+    ///
+    /// Like `accessibility`, this swaps in the pod's own `WidgetId` before
+    /// delegating, so `OperationCtx::widget_id` and `OperationCtx::is_focused`
+    /// are always correct for the widget currently being visited.
+    /// `focused_widget` is the id of the widget that currently has focus,
+    /// as tracked by the window, and is passed down unchanged.
+    pub fn apply_operation(&mut self, op: &mut dyn Operation, focused_widget: Option<WidgetId>) {
+        let mut child_ctx = OperationCtx {
+            widget_id: self.state.id,
+            focused_widget,
+        };
+        self.inner.apply_operation(&mut child_ctx, op);
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lenses, a way of focusing on a part of a larger data structure.
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use crate::Data;
+
+/// A `Lens` describes how to access a field `B` of a larger type `A`, so
+/// that a widget written in terms of `B` can be reused wherever a `A` is
+/// available.
+pub trait Lens<A, B> {
+    /// Get a value from `data` and pass it to `f`, returning the result.
+    fn with<V, F: FnOnce(&B) -> V>(&self, data: &A, f: F) -> V;
+
+    /// Get a mutable reference to the lensed value and pass it to `f`,
+    /// returning the result.
+    fn with_mut<V, F: FnOnce(&mut B) -> V>(&self, data: &mut A, f: F) -> V;
+}
+
+/// Extension methods for the `Lens` trait, providing combinators for
+/// building derived lenses.
+pub trait LensExt<A, B>: Lens<A, B> {
+    /// Combine this lens with a second, `B -> C` lens, to produce a
+    /// lens that goes directly from `A` to `C`.
+    fn then<C, L: Lens<B, C>>(self, other: L) -> Then<Self, L, B>
+    where
+        Self: Sized,
+    {
+        Then::new(self, other)
+    }
+
+    /// Map this lens's value through a pair of (fallible, in the sense
+    /// that `get` may e.g. wrap in `Option`) conversion functions, to
+    /// expose it as a different type.
+    fn map<C, Get, Put>(self, get: Get, put: Put) -> Then<Self, Map<Get, Put>, B>
+    where
+        Self: Sized,
+        Get: Fn(&B) -> C,
+        Put: Fn(&mut B, C),
+    {
+        self.then(Map::new(get, put))
+    }
+
+    /// Wrap this lens in a memoizing, read-only projection: `project` is
+    /// only re-run when the upstream `B` fails [`Data::same`] against the
+    /// value it was last run on, and the derived `C` only reports as
+    /// changed (for the purposes of the widget it's bound to) when the
+    /// freshly computed value itself fails `Data::same` against the
+    /// previously cached one.
+    ///
+    /// This is the read-only counterpart to [`map`](LensExt::map): where
+    /// `map` recomputes on every pass, `computed` skips re-running
+    /// `project` when the upstream `B` is unchanged. The dirty-tracking
+    /// payoff comes from pairing this with [`LensWrap`](crate::widget::LensWrap),
+    /// whose `update` compares the lensed old and new values with
+    /// `Data::same` *before* touching the wrapped widget: if `project`
+    /// produced an unchanged `C`, the wrapped subtree is skipped entirely
+    /// instead of being rebuilt any time the source data changes at all.
+    fn computed<C: Data, F: Fn(&B) -> C>(self, project: F) -> Then<Self, Computed<B, C, F>, B>
+    where
+        Self: Sized,
+        B: Data,
+    {
+        self.then(Computed::new(project))
+    }
+}
+
+impl<A, B, L: Lens<A, B>> LensExt<A, B> for L {}
+
+/// A lens that maps a field through a pair of conversion closures.
+pub struct Map<Get, Put> {
+    get: Get,
+    put: Put,
+}
+
+impl<Get, Put> Map<Get, Put> {
+    pub fn new<A, B>(get: Get, put: Put) -> Self
+    where
+        Get: Fn(&A) -> B,
+        Put: Fn(&mut A, B),
+    {
+        Map { get, put }
+    }
+}
+
+impl<A, B, Get: Fn(&A) -> B, Put: Fn(&mut A, B)> Lens<A, B> for Map<Get, Put> {
+    fn with<V, F: FnOnce(&B) -> V>(&self, data: &A, f: F) -> V {
+        f(&(self.get)(data))
+    }
+
+    fn with_mut<V, F: FnOnce(&mut B) -> V>(&self, data: &mut A, f: F) -> V {
+        let mut value = (self.get)(data);
+        let result = f(&mut value);
+        (self.put)(data, value);
+        result
+    }
+}
+
+/// The last source/output pair a [`Computed`] lens produced, so a repeat
+/// call with an unchanged (by `Data::same`) source can skip `project`
+/// entirely.
+struct Cache<A, B> {
+    source: A,
+    value: B,
+}
+
+/// A memoizing, read-only lens produced by [`LensExt::computed`].
+///
+/// The cache lives directly in this wrapper (behind a `RefCell`, since
+/// `Lens::with` only takes `&self`), as a single slot: it holds the one
+/// most recent source/output pair, not a table keyed by caller. That's
+/// exactly right for the common case this exists for — one `Computed`
+/// instance built for one [`LensWrap`](crate::widget::LensWrap), i.e. one
+/// widget — since then "most recent" and "this widget's" are the same
+/// thing. It stops being right if the *same* `Computed` instance (not a
+/// fresh one built the same way) is reused across multiple call sites
+/// bound to different data, e.g. by cloning one into every row of a list:
+/// each row's differing source data invalidates every other row's cached
+/// entry on every pass, so the cache thrashes instead of memoizing anything.
+/// Build a fresh `Computed` per call site (the same way a fresh `LensWrap`
+/// is already built per widget) rather than sharing one.
+pub struct Computed<A, B, F> {
+    project: F,
+    cache: RefCell<Option<Cache<A, B>>>,
+}
+
+impl<A, B, F> Computed<A, B, F> {
+    fn new(project: F) -> Self {
+        Computed {
+            project,
+            cache: RefCell::new(None),
+        }
+    }
+}
+
+impl<A: Data, B: Data, F: Fn(&A) -> B> Lens<A, B> for Computed<A, B, F> {
+    fn with<V, G: FnOnce(&B) -> V>(&self, data: &A, f: G) -> V {
+        // Release the borrow before calling `f`: `LensWrap::update` nests a
+        // second `with` on this same lens inside `f`.
+        let value = {
+            let mut cache = self.cache.borrow_mut();
+            let stale = match &*cache {
+                Some(cached) => !cached.source.same(data),
+                None => true,
+            };
+            if stale {
+                *cache = Some(Cache {
+                    source: data.clone(),
+                    value: (self.project)(data),
+                });
+            }
+            cache.as_ref().unwrap().value.clone()
+        };
+        f(&value)
+    }
+
+    fn with_mut<V, G: FnOnce(&mut B) -> V>(&self, data: &mut A, f: G) -> V {
+        // `Computed` is a read-only projection: give `f` a scratch copy of
+        // the (possibly still-cached) value rather than inventing a way to
+        // write a derived value back into its source.
+        let mut value = self.with(data, |v| v.clone());
+        f(&mut value)
+    }
+}
+
+/// The composition of two lenses, `A -> B` then `B -> C`.
+pub struct Then<L1, L2, B> {
+    left: L1,
+    right: L2,
+    _marker: PhantomData<B>,
+}
+
+impl<L1, L2, B> Then<L1, L2, B> {
+    pub fn new<A, C>(left: L1, right: L2) -> Self
+    where
+        L1: Lens<A, B>,
+        L2: Lens<B, C>,
+    {
+        Then {
+            left,
+            right,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A, B, C, L1: Lens<A, B>, L2: Lens<B, C>> Lens<A, C> for Then<L1, L2, B> {
+    fn with<V, F: FnOnce(&C) -> V>(&self, data: &A, f: F) -> V {
+        self.left.with(data, |b| self.right.with(b, f))
+    }
+
+    fn with_mut<V, F: FnOnce(&mut C) -> V>(&self, data: &mut A, f: F) -> V {
+        self.left.with_mut(data, |b| self.right.with_mut(b, f))
+    }
+}
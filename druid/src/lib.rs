@@ -0,0 +1,29 @@
+// Copyright 2018 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Druid is a data-first Rust-native UI toolkit.
+
+mod contexts;
+mod core;
+mod lens;
+pub mod widget;
+mod window;
+
+pub use contexts::{
+    AccessCtx, AccessNode, EventCtx, LayoutCtx, LifeCycleCtx, OperationCtx, PaintCtx, UpdateCtx,
+};
+pub use core::WidgetPod;
+pub use lens::{Lens, LensExt};
+pub use widget::Widget;
+pub use window::WindowRoot;
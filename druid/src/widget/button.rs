@@ -0,0 +1,73 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A clickable button with a text label.
+
+use accesskit::{NodeId, Role};
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, AccessNode, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Size, UpdateCtx, Widget,
+};
+
+/// A button that runs `action` when it is the target of an
+/// `Event::AccessClick` (an AccessKit `Default` action). Pointer input is
+/// not handled.
+pub struct Button<T> {
+    label: String,
+    action: Box<dyn FnMut(&mut EventCtx, &mut T, &Env)>,
+}
+
+impl<T> Button<T> {
+    /// Create a new button with the given label, running `action` on click.
+    pub fn new(
+        label: impl Into<String>,
+        action: impl FnMut(&mut EventCtx, &mut T, &Env) + 'static,
+    ) -> Self {
+        Button {
+            label: label.into(),
+            action: Box::new(action),
+        }
+    }
+}
+
+impl<T> Widget<T> for Button<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::AccessClick = event {
+            if ctx.is_target() {
+                (self.action)(ctx, data, env);
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {}
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
+        Size::new(bc.max().width.min(72.0).max(bc.min().width), bc.max().height.min(24.0).max(bc.min().height))
+    }
+
+    fn paint(&mut self, _paint_ctx: &mut PaintCtx, _data: &T, _env: &Env) {}
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, _data: &T, _env: &Env) -> Vec<NodeId> {
+        vec![ctx.push_node(AccessNode::new(Role::Button).with_label(self.label.clone()))]
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        op.focusable(ctx.widget_id(), ctx.is_focused());
+    }
+}
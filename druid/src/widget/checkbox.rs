@@ -0,0 +1,70 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A checkbox with a text label.
+
+use accesskit::{NodeId, Role};
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, AccessNode, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Size, UpdateCtx, Widget,
+};
+
+/// A labelled checkbox bound to a `bool`.
+pub struct Checkbox {
+    label: String,
+}
+
+impl Checkbox {
+    /// Create a new checkbox with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Checkbox {
+            label: label.into(),
+        }
+    }
+}
+
+impl Widget<bool> for Checkbox {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut bool, _env: &Env) {
+        if let Event::AccessClick = event {
+            if ctx.is_target() {
+                *data = !*data;
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &bool, _env: &Env) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &bool, _data: &bool, _env: &Env) {}
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &bool, _env: &Env) -> Size {
+        Size::new(bc.max().width.min(18.0).max(bc.min().width), 18.0.max(bc.min().height))
+    }
+
+    fn paint(&mut self, _paint_ctx: &mut PaintCtx, _data: &bool, _env: &Env) {}
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &bool, _env: &Env) -> Vec<NodeId> {
+        // `Role::CheckBox` is accesskit's toggle role.
+        let node = AccessNode::new(Role::CheckBox)
+            .with_label(self.label.clone())
+            .with_checked(*data);
+        vec![ctx.push_node(node)]
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        op.focusable(ctx.widget_id(), ctx.is_focused());
+    }
+}
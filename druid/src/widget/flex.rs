@@ -0,0 +1,543 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that arranges its children in a one-dimensional array.
+
+use accesskit::{NodeId, Role};
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, AccessNode, BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Point, Size, UpdateCtx, Widget, WidgetPod,
+};
+
+/// A container with either horizontal or vertical layout.
+pub struct Flex<T> {
+    direction: Axis,
+    cross_alignment: CrossAxisAlignment,
+    main_alignment: MainAxisAlignment,
+    fill_major_axis: bool,
+    children: Vec<Child<T>>,
+}
+
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+enum Child<T> {
+    Fixed {
+        widget: WidgetPod<T, Box<dyn Widget<T>>>,
+    },
+    Flex {
+        widget: WidgetPod<T, Box<dyn Widget<T>>>,
+        params: FlexParams,
+    },
+    FixedSpacer(f64),
+    FlexSpacer(f64),
+}
+
+/// An axis-independent description of how a flex child's main-axis space
+/// should be computed: whether it is forced to exactly fill its allotment,
+/// or only offered up to that much and allowed to come in smaller.
+///
+/// This mirrors Flutter's `FlexFit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum FlexFit {
+    /// The child is given a major-axis constraint whose min and max are
+    /// both equal to its allotment: it is stretched to exactly fill it.
+    /// This is the default, and was the only behavior before `FlexFit`
+    /// existed.
+    Tight,
+    /// The child is given a major-axis constraint of `0..=allotment`: it
+    /// may be smaller than its allotment if its content doesn't need the
+    /// space, with the remainder handled like any other leftover space,
+    /// according to `MainAxisAlignment`.
+    Loose,
+}
+
+/// Parameters for a flex child: its flex factor, and whether it should be
+/// tightly or loosely fit within the major-axis space that factor earns it.
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+pub struct FlexParams {
+    pub(crate) flex: f64,
+    pub(crate) fit: FlexFit,
+}
+
+impl FlexParams {
+    /// Create parameters with the given `flex` factor and a `Tight` fit.
+    pub fn new(flex: f64) -> Self {
+        FlexParams {
+            flex,
+            fit: FlexFit::Tight,
+        }
+    }
+
+    /// Create parameters with the given `flex` factor and `fit`.
+    pub fn with_fit(flex: f64, fit: FlexFit) -> Self {
+        FlexParams { flex, fit }
+    }
+}
+
+impl From<f64> for FlexParams {
+    fn from(flex: f64) -> Self {
+        FlexParams::new(flex)
+    }
+}
+
+/// Options for aligning children on the cross axis.
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+pub enum CrossAxisAlignment {
+    Start,
+    Center,
+    End,
+    Baseline,
+    Fill,
+}
+
+/// Options for aligning children on the main axis.
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+pub enum MainAxisAlignment {
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceEvenly,
+    SpaceAround,
+}
+
+impl<T: Data> Flex<T> {
+    /// Create a new horizontal stack.
+    pub fn row() -> Self {
+        Flex {
+            direction: Axis::Horizontal,
+            cross_alignment: CrossAxisAlignment::Center,
+            main_alignment: MainAxisAlignment::Start,
+            fill_major_axis: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a new vertical stack.
+    pub fn column() -> Self {
+        Flex {
+            direction: Axis::Vertical,
+            cross_alignment: CrossAxisAlignment::Center,
+            main_alignment: MainAxisAlignment::Start,
+            fill_major_axis: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the cross-axis alignment, returning `self`.
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.cross_alignment = alignment;
+        self
+    }
+
+    /// Set the main-axis alignment, returning `self`.
+    pub fn main_axis_alignment(mut self, alignment: MainAxisAlignment) -> Self {
+        self.main_alignment = alignment;
+        self
+    }
+
+    /// Whether the container should force its main-axis size to be as large
+    /// as possible, returning `self`.
+    pub fn must_fill_main_axis(mut self, fill: bool) -> Self {
+        self.fill_major_axis = fill;
+        self
+    }
+
+    /// Builder-style variant of [`add_child`].
+    ///
+    /// [`add_child`]: #method.add_child
+    pub fn with_child(mut self, child: impl Widget<T> + 'static, params: impl Into<f64>) -> Self {
+        self.add_child(child, params);
+        self
+    }
+
+    /// Builder-style variant of [`add_flex_child`].
+    ///
+    /// `params` can be either an `f64` flex factor (for the previous,
+    /// always-[`Tight`](FlexFit::Tight) behavior) or an explicit
+    /// [`FlexParams`].
+    ///
+    /// [`add_flex_child`]: #method.add_flex_child
+    pub fn with_flex_child(
+        mut self,
+        child: impl Widget<T> + 'static,
+        params: impl Into<FlexParams>,
+    ) -> Self {
+        self.add_flex_child(child, params);
+        self
+    }
+
+    /// Builder-style variant of [`add_spacer`].
+    pub fn with_spacer(mut self, len: f64) -> Self {
+        self.add_spacer(len);
+        self
+    }
+
+    /// Builder-style variant of [`add_flex_spacer`].
+    pub fn with_flex_spacer(mut self, flex: f64) -> Self {
+        self.add_flex_spacer(flex);
+        self
+    }
+
+    /// Add a child widget, with an `f64` flex factor.
+    ///
+    /// A factor of `0.0` (the common case) gives the child exactly the
+    /// space it asks for; a nonzero factor is equivalent to calling
+    /// [`add_flex_child`] with a [`Tight`](FlexFit::Tight) fit, kept here so
+    /// existing call sites don't need to change.
+    ///
+    /// [`add_flex_child`]: #method.add_flex_child
+    pub fn add_child(&mut self, child: impl Widget<T> + 'static, flex: impl Into<f64>) {
+        let flex = flex.into();
+        if flex == 0.0 {
+            self.children.push(Child::Fixed {
+                widget: WidgetPod::new(child).boxed(),
+            });
+        } else {
+            self.add_flex_child(child, flex);
+        }
+    }
+
+    /// Add a flexible child widget.
+    ///
+    /// `params` can be either an `f64` flex factor, in which case the child
+    /// is fit [`Tight`](FlexFit::Tight) as before, or a [`FlexParams`]
+    /// requesting [`Loose`](FlexFit::Loose) fitting.
+    pub fn add_flex_child(&mut self, child: impl Widget<T> + 'static, params: impl Into<FlexParams>) {
+        let child = Child::Flex {
+            widget: WidgetPod::new(child).boxed(),
+            params: params.into(),
+        };
+        self.children.push(child);
+    }
+
+    /// Add a fixed-size spacer.
+    pub fn add_spacer(&mut self, len: f64) {
+        self.children.push(Child::FixedSpacer(len.max(0.0)));
+    }
+
+    /// Add a flexible spacer.
+    pub fn add_flex_spacer(&mut self, flex: f64) {
+        self.children.push(Child::FlexSpacer(flex.max(0.0)));
+    }
+}
+
+impl<T: Data> Widget<T> for Flex<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        for child in self.children.iter_mut().filter_map(Child::widget_mut) {
+            child.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        for child in self.children.iter_mut().filter_map(Child::widget_mut) {
+            child.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        for child in self.children.iter_mut().filter_map(Child::widget_mut) {
+            child.update(ctx, old_data, data, env);
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let major_total = self.major(bc.max());
+        let minor_total = self.minor(bc.max());
+
+        let total_flex: f64 = self
+            .children
+            .iter()
+            .map(|c| match c {
+                Child::Flex { params, .. } => params.flex,
+                Child::FlexSpacer(flex) => *flex,
+                _ => 0.0,
+            })
+            .sum();
+
+        let fixed_major: f64 = self
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                Child::FixedSpacer(len) => Some(*len),
+                _ => None,
+            })
+            .sum();
+
+        // Non-flex children get exactly the space they ask for; measure
+        // them first so we know how much major-axis space is left to
+        // divide among the flex children.
+        let mut fixed_children_major = 0.0;
+        for child in &mut self.children {
+            if let Child::Fixed { widget } = child {
+                let child_bc = self.loose_constraints(bc, minor_total);
+                let size = widget.layout(ctx, &child_bc, data, env);
+                fixed_children_major += self.major(size);
+            }
+        }
+
+        let remaining_major = (major_total - fixed_major - fixed_children_major).max(0.0);
+        let px_per_flex = if total_flex > 0.0 {
+            remaining_major / total_flex
+        } else {
+            0.0
+        };
+
+        let mut children_major = fixed_major + fixed_children_major;
+        for child in &mut self.children {
+            match child {
+                Child::Flex { widget, params } => {
+                    let allotment = (px_per_flex * params.flex).max(0.0);
+                    let child_bc = match params.fit {
+                        // Tight: min and max major extent both equal the
+                        // allotment, so the child is stretched to fill it.
+                        FlexFit::Tight => self.tight_major_constraints(bc, minor_total, allotment),
+                        // Loose: min major is zero, max major is the
+                        // allotment, so the child only takes what it needs.
+                        FlexFit::Loose => self.loose_major_constraints(bc, minor_total, allotment),
+                    };
+                    let size = widget.layout(ctx, &child_bc, data, env);
+                    children_major += self.major(size);
+                }
+                // A flex spacer doesn't lay out a widget, but it still
+                // earns and reserves its share of `px_per_flex`, the same
+                // as a flex child with a `Tight` fit would.
+                Child::FlexSpacer(flex) => {
+                    children_major += px_per_flex * *flex;
+                }
+                _ => {}
+            }
+        }
+
+        // Once any flex child or flex spacer has earned a share of
+        // `remaining_major`, the container has implicitly committed to
+        // `major_total`: any of that allotment a `Loose`-fit child declined
+        // to use is slack the container itself reclaimed, not space that
+        // should vanish. Only a container with zero total flex — every
+        // child `Fixed`, nothing to redistribute — and that hasn't also
+        // been told to fill the major axis should actually shrink to its
+        // children's combined extent.
+        let major_size = if self.fill_major_axis || total_flex > 0.0 {
+            children_major.max(major_total)
+        } else {
+            children_major
+        };
+
+        self.place_children(children_major, major_size, minor_total, px_per_flex);
+        self.make_size(major_size, minor_total)
+    }
+
+    fn paint(&mut self, paint_ctx: &mut PaintCtx, data: &T, env: &Env) {
+        for child in self.children.iter_mut().filter_map(Child::widget_mut) {
+            child.paint(paint_ctx, data, env);
+        }
+    }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) -> Vec<NodeId> {
+        // `Flex` has no semantics of its own beyond grouping, so it pushes a
+        // generic container node (`Role::GenericContainer`) whose children
+        // are whatever ids its own children's `accessibility` calls
+        // returned, visited in the same order layout already visits them
+        // in. Pushing this node (rather than only forwarding to children,
+        // as before) is what makes the tree accesskit sees actually nest —
+        // without it, every leaf ends up a direct, unordered child of
+        // whatever ancestor last pushed a node.
+        let children: Vec<NodeId> = self
+            .children
+            .iter_mut()
+            .filter_map(Child::widget_mut)
+            .flat_map(|child| child.accessibility(ctx, data, env))
+            .collect();
+        let node = AccessNode::new(Role::GenericContainer).with_children(children);
+        vec![ctx.push_node(node)]
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        op.container(ctx.widget_id());
+        for child in self.children.iter_mut().filter_map(Child::widget_mut) {
+            child.apply_operation(op, ctx.focused_widget);
+        }
+    }
+}
+
+impl<T> Child<T> {
+    fn widget_mut(&mut self) -> Option<&mut WidgetPod<T, Box<dyn Widget<T>>>> {
+        match self {
+            Child::Fixed { widget } | Child::Flex { widget, .. } => Some(widget),
+            Child::FixedSpacer(_) | Child::FlexSpacer(_) => None,
+        }
+    }
+}
+
+impl<T: Data> Flex<T> {
+    fn major(&self, size: Size) -> f64 {
+        match self.direction {
+            Axis::Horizontal => size.width,
+            Axis::Vertical => size.height,
+        }
+    }
+
+    fn minor(&self, size: Size) -> f64 {
+        match self.direction {
+            Axis::Horizontal => size.height,
+            Axis::Vertical => size.width,
+        }
+    }
+
+    fn make_size(&self, major: f64, minor: f64) -> Size {
+        match self.direction {
+            Axis::Horizontal => Size::new(major, minor),
+            Axis::Vertical => Size::new(minor, major),
+        }
+    }
+
+    fn loose_constraints(&self, bc: &BoxConstraints, minor: f64) -> BoxConstraints {
+        self.loose_major_constraints(bc, minor, self.major(bc.max()))
+    }
+
+    fn loose_major_constraints(
+        &self,
+        _bc: &BoxConstraints,
+        minor_total: f64,
+        major_max: f64,
+    ) -> BoxConstraints {
+        self.major_constraints(minor_total, 0.0, major_max)
+    }
+
+    fn tight_major_constraints(
+        &self,
+        _bc: &BoxConstraints,
+        minor_total: f64,
+        major: f64,
+    ) -> BoxConstraints {
+        self.major_constraints(minor_total, major, major)
+    }
+
+    /// Build the constraints for a child's `layout` call: `major_min..major_max`
+    /// on the main axis, and on the cross axis, `minor_total` as the max and,
+    /// for [`CrossAxisAlignment::Fill`], `minor_total` as the min too, so a
+    /// filled child is forced to the container's full cross extent.
+    fn major_constraints(&self, minor_total: f64, major_min: f64, major_max: f64) -> BoxConstraints {
+        let minor_min = match self.cross_alignment {
+            CrossAxisAlignment::Fill => minor_total,
+            _ => 0.0,
+        };
+        match self.direction {
+            Axis::Horizontal => BoxConstraints::new(
+                Size::new(major_min, minor_min),
+                Size::new(major_max, minor_total),
+            ),
+            Axis::Vertical => BoxConstraints::new(
+                Size::new(minor_min, major_min),
+                Size::new(minor_total, major_max),
+            ),
+        }
+    }
+
+    /// Position each child along the major axis, honoring `MainAxisAlignment`
+    /// for the slack between `children_major` (the children's own extents,
+    /// including any reserved `FlexSpacer` allotments) and `major_size` (the
+    /// container's final major-axis size) — and along the cross axis,
+    /// honoring `CrossAxisAlignment` against `minor_total`.
+    ///
+    /// A `Loose`-fit child that came back smaller than its allotment is the
+    /// common source of major-axis slack; it's folded into the same
+    /// leftover-space handling as any other gap, rather than being treated
+    /// specially.
+    ///
+    /// `between` is only inserted after an actual widget (`Fixed`/`Flex`),
+    /// not after a spacer: a spacer already exists to reserve an exact,
+    /// explicit gap, so also counting it as an "item" that earns its own
+    /// `MainAxisAlignment` gap on top would double up the spacing around
+    /// it.
+    fn place_children(&mut self, children_major: f64, major_size: f64, minor_total: f64, px_per_flex: f64) {
+        let slack = (major_size - children_major).max(0.0);
+        let widget_count = self
+            .children
+            .iter()
+            .filter(|c| matches!(c, Child::Fixed { .. } | Child::Flex { .. }))
+            .count();
+        let (leading, between) = self.main_axis_spacing(slack, widget_count);
+
+        let mut major = leading;
+        for child in self.children.iter_mut() {
+            match child {
+                Child::Fixed { widget } | Child::Flex { widget, .. } => {
+                    let size = widget.layout_rect().size();
+                    let cross = Self::cross_position(self.cross_alignment, self.minor(size), minor_total);
+                    widget.set_origin(self.make_point(major, cross));
+                    major += self.major(size) + between;
+                }
+                Child::FixedSpacer(len) => major += *len,
+                Child::FlexSpacer(flex) => major += px_per_flex * *flex,
+            }
+        }
+    }
+
+    /// The `(leading, between)` offsets `place_children` starts from and
+    /// advances by, for `slack` major-axis pixels distributed over `count`
+    /// actual widgets (spacers don't count — see `place_children`) per
+    /// `MainAxisAlignment`.
+    fn main_axis_spacing(&self, slack: f64, count: usize) -> (f64, f64) {
+        match self.main_alignment {
+            MainAxisAlignment::Start => (0.0, 0.0),
+            MainAxisAlignment::Center => (slack / 2.0, 0.0),
+            MainAxisAlignment::End => (slack, 0.0),
+            MainAxisAlignment::SpaceBetween => {
+                if count > 1 {
+                    (0.0, slack / (count - 1) as f64)
+                } else {
+                    (0.0, 0.0)
+                }
+            }
+            MainAxisAlignment::SpaceEvenly => {
+                let gap = slack / (count + 1) as f64;
+                (gap, gap)
+            }
+            MainAxisAlignment::SpaceAround => {
+                if count > 0 {
+                    let gap = slack / count as f64;
+                    (gap / 2.0, gap)
+                } else {
+                    (0.0, 0.0)
+                }
+            }
+        }
+    }
+
+    /// A child's cross-axis offset within `minor_total`, per `alignment`.
+    /// `Fill` children were already constrained to `minor_total` by
+    /// `major_constraints`, so they fall out at `0.0` along with `Start`;
+    /// `Baseline` isn't implemented (no widget reports a baseline yet), so
+    /// it behaves like `Start` rather than panicking on unmatched children.
+    fn cross_position(alignment: CrossAxisAlignment, child_minor: f64, minor_total: f64) -> f64 {
+        match alignment {
+            CrossAxisAlignment::Start | CrossAxisAlignment::Fill | CrossAxisAlignment::Baseline => 0.0,
+            CrossAxisAlignment::Center => ((minor_total - child_minor) / 2.0).max(0.0),
+            CrossAxisAlignment::End => (minor_total - child_minor).max(0.0),
+        }
+    }
+
+    fn make_point(&self, major: f64, minor: f64) -> Point {
+        match self.direction {
+            Axis::Horizontal => Point::new(major, minor),
+            Axis::Vertical => Point::new(minor, major),
+        }
+    }
+}
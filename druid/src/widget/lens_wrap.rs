@@ -0,0 +1,108 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that runs its child against a lensed view of the outer data.
+
+use std::marker::PhantomData;
+
+use accesskit::NodeId;
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, Lens, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Size, UpdateCtx, Widget, WidgetId,
+};
+
+/// A widget that adapts an inner `Widget<U>` to run within an outer `T`,
+/// via a [`Lens`]`<T, U>`.
+///
+/// On [`update`](Widget::update), the lensed old and new values are compared
+/// with [`Data::same`] before the inner widget is touched for *data*
+/// changes: if they match, the data half of this pass is skipped. This is
+/// what actually makes a lens built with [`computed`](crate::LensExt::computed)
+/// useful for avoiding spurious rebuilds — a `Computed` lens that recomputed
+/// to an unchanged value still produces a `U` equal (by `Data::same`) to the
+/// one `LensWrap` saw last time, so the comparison here short-circuits
+/// before `self.inner.update` ever runs for that reason. `update`'s
+/// signature has no `old_env`, so there's no equivalent lensed comparison
+/// to make for *env* changes; instead `LensWrap` remembers the last `Env` it
+/// saw itself, and calls through whenever that's stale, independent of
+/// whether the lensed data changed at all.
+pub struct LensWrap<T, U, L, W> {
+    inner: W,
+    lens: L,
+    last_env: Option<Env>,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<T, U, L: Lens<T, U>, W: Widget<U>> LensWrap<T, U, L, W> {
+    /// Wrap `inner`, which operates on `U`, so it can be used where a
+    /// `Widget<T>` is expected, via `lens`.
+    pub fn new(inner: W, lens: L) -> Self {
+        LensWrap {
+            inner,
+            lens,
+            last_env: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Data, U: Data, L: Lens<T, U>, W: Widget<U>> Widget<T> for LensWrap<T, U, L, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let Self { inner, lens, .. } = self;
+        lens.with_mut(data, |data| inner.event(ctx, event, data, env));
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        let Self { inner, lens, .. } = self;
+        lens.with(data, |data| inner.lifecycle(ctx, event, data, env));
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let Self { inner, lens, last_env, .. } = self;
+        let env_changed = !last_env.as_ref().map_or(false, |last| last.same(env));
+        *last_env = Some(env.clone());
+        lens.with(old_data, |old_data| {
+            lens.with(data, |data| {
+                if env_changed || !old_data.same(data) {
+                    inner.update(ctx, old_data, data, env);
+                }
+            })
+        });
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let Self { inner, lens, .. } = self;
+        lens.with(data, |data| inner.layout(ctx, bc, data, env))
+    }
+
+    fn paint(&mut self, paint_ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let Self { inner, lens, .. } = self;
+        lens.with(data, |data| inner.paint(paint_ctx, data, env));
+    }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) -> Vec<NodeId> {
+        let Self { inner, lens, .. } = self;
+        lens.with(data, |data| inner.accessibility(ctx, data, env))
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        self.inner.apply_operation(ctx, op);
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        self.inner.id()
+    }
+}
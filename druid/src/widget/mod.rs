@@ -0,0 +1,38 @@
+// Copyright 2018 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A collection of common widgets for Druid.
+
+#[allow(clippy::module_inception)]
+mod widget;
+mod button;
+mod checkbox;
+mod flex;
+mod lens_wrap;
+mod operation;
+mod slider;
+mod stepper;
+mod switch;
+mod text_box;
+
+pub use button::Button;
+pub use checkbox::Checkbox;
+pub use flex::{CrossAxisAlignment, Flex, FlexFit, FlexParams, MainAxisAlignment};
+pub use lens_wrap::LensWrap;
+pub use operation::{CountFocusables, FocusById, FocusTraversal, Operation};
+pub use slider::Slider;
+pub use stepper::Stepper;
+pub use switch::Switch;
+pub use text_box::TextBox;
+pub use widget::Widget;
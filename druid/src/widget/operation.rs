@@ -0,0 +1,174 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A generic mechanism for walking the live widget tree to query or mutate
+//! targeted widget state, without threading that state through `Data`.
+//!
+//! `ctx.apply_operation(op)` (see `EventCtx`) schedules `op` to be run from
+//! the root of the window once the current event has finished dispatching,
+//! in the same order `layout`
+//! already recurses in: containers report themselves via `container`
+//! before visiting each child, and focusable or text-input leaves report
+//! themselves via the matching hook. `Flex` forwards into its children, and
+//! wrappers like `LensWrap` into their inner widget, the same way they do for
+//! `accessibility`; focusable leaves like `TextBox`, `Button`, `Checkbox`,
+//! `Slider`, `Stepper`, and `Switch` report themselves instead.
+
+use crate::{EventCtx, WidgetId};
+
+/// A single-pass, read/write visitor over the widget tree.
+pub trait Operation {
+    /// Called for a container widget, before it forwards to its children.
+    #[allow(unused_variables)]
+    fn container(&mut self, id: WidgetId) {}
+
+    /// Called for a widget that participates in focus traversal.
+    #[allow(unused_variables)]
+    fn focusable(&mut self, id: WidgetId, is_focused: bool) {}
+
+    /// Called for a widget that accepts text input.
+    #[allow(unused_variables)]
+    fn text_input(&mut self, id: WidgetId) {}
+
+    /// Called once the whole tree has been visited, with the `EventCtx` of
+    /// the event that scheduled this operation, so the operation can act on
+    /// what it collected — e.g. [`FocusTraversal`] calls
+    /// `ctx.request_focus` here. The default is a no-op. An operation
+    /// scheduled through `EventCtx::apply_operation` is dropped right after
+    /// this call, so state gathered by one that keeps the default (like
+    /// [`CountFocusables`]) can only be read back by a caller that runs it
+    /// directly through `WidgetPod::apply_operation`.
+    #[allow(unused_variables)]
+    fn finish(&mut self, ctx: &mut EventCtx<'_, '_>) {}
+}
+
+/// Which direction a [`FocusTraversal`] should move in.
+#[derive(Clone, Copy)]
+enum Direction {
+    Next,
+    Previous,
+}
+
+/// Walks every focusable widget in tab order, and decides which one should
+/// receive focus next.
+///
+/// Built by [`focus_next`] or [`focus_previous`]; once it has visited the
+/// tree, [`FocusTraversal::target`] gives the widget id that should become
+/// focused (wrapping at either end of the tab order), and `finish` requests
+/// focus for it.
+pub struct FocusTraversal {
+    direction: Direction,
+    order: Vec<WidgetId>,
+    current: Option<usize>,
+}
+
+impl FocusTraversal {
+    /// Move focus to the next focusable widget in tree order, wrapping to
+    /// the first one if the last (or none) is currently focused.
+    pub fn focus_next() -> Self {
+        FocusTraversal {
+            direction: Direction::Next,
+            order: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// Move focus to the previous focusable widget in tree order, wrapping
+    /// to the last one if the first (or none) is currently focused.
+    pub fn focus_previous() -> Self {
+        FocusTraversal {
+            direction: Direction::Previous,
+            order: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// The widget that should receive focus, once the traversal has
+    /// visited the whole tree.
+    pub fn target(&self) -> Option<WidgetId> {
+        if self.order.is_empty() {
+            return None;
+        }
+        let next_index = match (self.direction, self.current) {
+            (Direction::Next, Some(i)) => (i + 1) % self.order.len(),
+            (Direction::Previous, Some(i)) => (i + self.order.len() - 1) % self.order.len(),
+            (Direction::Next, None) => 0,
+            (Direction::Previous, None) => self.order.len() - 1,
+        };
+        self.order.get(next_index).copied()
+    }
+}
+
+impl Operation for FocusTraversal {
+    fn focusable(&mut self, id: WidgetId, is_focused: bool) {
+        if is_focused {
+            self.current = Some(self.order.len());
+        }
+        self.order.push(id);
+    }
+
+    fn finish(&mut self, ctx: &mut EventCtx<'_, '_>) {
+        if let Some(id) = self.target() {
+            ctx.request_focus(id);
+        }
+    }
+}
+
+/// Counts the number of focusable widgets currently in the tree.
+#[derive(Default)]
+pub struct CountFocusables {
+    count: usize,
+}
+
+impl CountFocusables {
+    pub fn new() -> Self {
+        CountFocusables::default()
+    }
+
+    /// The number of focusable widgets seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl Operation for CountFocusables {
+    fn focusable(&mut self, _id: WidgetId, _is_focused: bool) {
+        self.count += 1;
+    }
+}
+
+/// Checks whether `id` identifies a focusable widget currently in the tree.
+pub struct FocusById {
+    id: WidgetId,
+    found: bool,
+}
+
+impl FocusById {
+    pub fn new(id: WidgetId) -> Self {
+        FocusById { id, found: false }
+    }
+
+    /// Whether the requested widget was found among the focusable widgets.
+    pub fn found(&self) -> bool {
+        self.found
+    }
+}
+
+impl Operation for FocusById {
+    fn focusable(&mut self, id: WidgetId, _is_focused: bool) {
+        if id == self.id {
+            self.found = true;
+        }
+    }
+}
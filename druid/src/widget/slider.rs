@@ -0,0 +1,86 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A draggable slider over a bounded range of `f64` values.
+
+use accesskit::{NodeId, Role};
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, AccessNode, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Size, UpdateCtx, Widget,
+};
+
+/// A slider bound to an `f64`, ranging over `min..=max`.
+pub struct Slider {
+    min: f64,
+    max: f64,
+}
+
+impl Slider {
+    /// Create a new slider over the default `0.0..=1.0` range.
+    pub fn new() -> Self {
+        Slider { min: 0.0, max: 1.0 }
+    }
+
+    /// Set the minimum value, returning `self`.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value, returning `self`.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Slider::new()
+    }
+}
+
+impl Widget<f64> for Slider {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut f64, _env: &Env) {
+        if let Event::AccessSetValue(value) = event {
+            if ctx.is_target() {
+                *data = value.clamp(self.min, self.max);
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &f64, _env: &Env) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &f64, _data: &f64, _env: &Env) {}
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &f64, _env: &Env) -> Size {
+        Size::new(bc.max().width.max(bc.min().width), 14.0.max(bc.min().height))
+    }
+
+    fn paint(&mut self, _paint_ctx: &mut PaintCtx, _data: &f64, _env: &Env) {}
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &f64, _env: &Env) -> Vec<NodeId> {
+        let node = AccessNode::new(Role::Slider)
+            .with_numeric_value(*data)
+            .with_numeric_range(self.min, self.max);
+        vec![ctx.push_node(node)]
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        op.focusable(ctx.widget_id(), ctx.is_focused());
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An on/off toggle switch.
+
+use accesskit::{NodeId, Role};
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, AccessNode, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Size, UpdateCtx, Widget,
+};
+
+/// A toggle switch bound to a `bool`.
+#[derive(Default)]
+pub struct Switch;
+
+impl Switch {
+    /// Create a new switch.
+    pub fn new() -> Self {
+        Switch
+    }
+}
+
+impl Widget<bool> for Switch {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut bool, _env: &Env) {
+        if let Event::AccessClick = event {
+            if ctx.is_target() {
+                *data = !*data;
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &bool, _env: &Env) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &bool, _data: &bool, _env: &Env) {}
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &bool, _env: &Env) -> Size {
+        Size::new(bc.max().width.min(32.0).max(bc.min().width), 18.0.max(bc.min().height))
+    }
+
+    fn paint(&mut self, _paint_ctx: &mut PaintCtx, _data: &bool, _env: &Env) {}
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &bool, _env: &Env) -> Vec<NodeId> {
+        // `Switch` shares `Checkbox`'s toggle semantics; accesskit models
+        // both as `Role::Switch`/`Role::CheckBox` rather than a shared
+        // toggle role, and a switch is the more specific of the two.
+        vec![ctx.push_node(AccessNode::new(Role::Switch).with_checked(*data))]
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        op.focusable(ctx.widget_id(), ctx.is_focused());
+    }
+}
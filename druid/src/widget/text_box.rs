@@ -0,0 +1,62 @@
+// Copyright 2019 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A single-line editable text field.
+
+use accesskit::{NodeId, Role};
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, AccessNode, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle,
+    LifeCycleCtx, OperationCtx, PaintCtx, Size, UpdateCtx, Widget,
+};
+
+/// A single-line text field bound to a `String`.
+#[derive(Default)]
+pub struct TextBox;
+
+impl TextBox {
+    /// Create a new, empty text box.
+    pub fn new() -> Self {
+        TextBox
+    }
+}
+
+impl Widget<String> for TextBox {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut String, _env: &Env) {
+        // `AccessSetValue` only ever carries the numeric `f64` payload
+        // accesskit's `ActionData::Value` holds; accepting string input
+        // from assistive tech (and from typed keyboard input) needs its
+        // own `Event` variant, which doesn't exist yet.
+    }
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &String, _env: &Env) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &String, _data: &String, _env: &Env) {}
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &String, _env: &Env) -> Size {
+        Size::new(bc.max().width.max(bc.min().width), 24.0.max(bc.min().height))
+    }
+
+    fn paint(&mut self, _paint_ctx: &mut PaintCtx, _data: &String, _env: &Env) {}
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &String, _env: &Env) -> Vec<NodeId> {
+        vec![ctx.push_node(AccessNode::new(Role::TextInput).with_value(data.clone()))]
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        op.focusable(ctx.widget_id(), ctx.is_focused());
+        op.text_input(ctx.widget_id());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The core `Widget` trait.
+
+use accesskit::NodeId;
+
+use crate::widget::Operation;
+use crate::{
+    AccessCtx, BoxConstraints, Env, Event, EventCtx, LayoutCtx, LifeCycle, LifeCycleCtx,
+    OperationCtx, PaintCtx, Size, UpdateCtx, WidgetId,
+};
+
+/// The trait implemented by all widgets.
+///
+/// All appearance and behavior for a widget is encapsulated in an
+/// implementation of this trait. Widgets are generic over the type of data
+/// they are given, and this type is carried as the `T` type parameter.
+pub trait Widget<T> {
+    /// Handle an event.
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env);
+
+    /// Handle a life cycle notification.
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env);
+
+    /// Called whenever data or env changes.
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env);
+
+    /// Compute layout and return the size the widget needs.
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size;
+
+    /// Paint the widget.
+    fn paint(&mut self, paint_ctx: &mut PaintCtx, data: &T, env: &Env);
+
+    /// Report this widget's presence, and that of any children, to the
+    /// platform accessibility tree, returning the ids of the node(s) it
+    /// pushed so a parent container can list them as its own children.
+    ///
+    /// The default implementation pushes nothing and returns an empty list,
+    /// which is correct for decorative widgets that have no semantic role.
+    /// Leaf controls should push a single node (via [`AccessCtx::push_node`])
+    /// describing their role, label/value, and state, and return its id.
+    /// Containers should call this on each child first, collect the ids they
+    /// return, and attach them to their own node via
+    /// [`AccessNode::with_children`](crate::AccessNode::with_children) so
+    /// the accessibility tree mirrors the shape `layout` already walks.
+    #[allow(unused_variables)]
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) -> Vec<NodeId> {
+        Vec::new()
+    }
+
+    /// Visit this widget (and, for containers, its children) with a tree
+    /// query or mutation requested via [`EventCtx::apply_operation`].
+    ///
+    /// The default implementation does nothing, which is correct for
+    /// widgets that are neither containers nor focusable. Containers
+    /// should call `op.container(ctx.widget_id())` and then forward to each
+    /// child's `WidgetPod::apply_operation`; focusable leaves should call
+    /// `op.focusable(ctx.widget_id(), ctx.is_focused())`.
+    ///
+    /// [`EventCtx::apply_operation`]: struct.EventCtx.html#method.apply_operation
+    #[allow(unused_variables)]
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {}
+
+    /// Get the (optional) explicit `WidgetId` for this widget.
+    ///
+    /// Used internally by `WidgetPod`; most widgets can leave this as the
+    /// default.
+    fn id(&self) -> Option<WidgetId> {
+        None
+    }
+}
+
+impl<T> Widget<T> for Box<dyn Widget<T>> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.as_mut().event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.as_mut().lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.as_mut().update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.as_mut().layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, paint_ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.as_mut().paint(paint_ctx, data, env)
+    }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, data: &T, env: &Env) -> Vec<NodeId> {
+        self.as_mut().accessibility(ctx, data, env)
+    }
+
+    fn apply_operation(&mut self, ctx: &mut OperationCtx, op: &mut dyn Operation) {
+        self.as_mut().apply_operation(ctx, op)
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        self.as_ref().id()
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2018 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The root of a window's widget tree, and the driver for passes (like
+//! `Operation`) that need to run from there rather than from whatever
+//! widget happened to request them.
+
+use accesskit::{Tree, TreeUpdate};
+
+use crate::{AccessCtx, Env, Event, EventCtx, Widget, WidgetId, WidgetPod, WindowHandle};
+
+/// Owns the root widget of a window, plus the state (currently: which
+/// widget has focus) that a single widget's `EventCtx` can't hold on its
+/// own.
+pub struct WindowRoot<T, W> {
+    root: WidgetPod<T, W>,
+    focused_widget: Option<WidgetId>,
+}
+
+impl<T, W: Widget<T>> WindowRoot<T, W> {
+    pub fn new(root: W) -> Self {
+        WindowRoot {
+            root: WidgetPod::new(root),
+            focused_widget: None,
+        }
+    }
+
+    /// The `WidgetId` of the root widget, for starting a fresh
+    /// `EventCtx::new` pass.
+    pub fn root_id(&self) -> WidgetId {
+        self.root.id()
+    }
+
+    /// Dispatch `event` to every widget that cares to look at it, then drain
+    /// and run any `Operation` it scheduled via `ctx.apply_operation`, and
+    /// apply the resulting focus change (if any) before the next pass.
+    ///
+    /// This is the one place `EventCtx::pending_operation` is read: the
+    /// root widget's own `event` call returns first (so e.g. the `Tab`
+    /// handler in the Flex demo gets to call `ctx.set_handled()` and mark
+    /// `ctx.apply_operation(...)` in the same pass), then the operation is
+    /// walked over the whole tree from here, and `Operation::finish` is
+    /// given the chance to call `ctx.request_focus`.
+    pub fn do_event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        ctx.target = None;
+        self.root.event(ctx, event, data, env);
+
+        if let Some(mut op) = ctx.pending_operation.take() {
+            self.root.apply_operation(op.as_mut(), self.focused_widget);
+            op.finish(ctx);
+        }
+
+        if let Some(id) = ctx.request_focus.take() {
+            self.focused_widget = Some(id);
+        }
+    }
+
+    /// Like [`do_event`](Self::do_event), but `event` is only meant for the
+    /// widget identified by `target` — e.g. one half of a pair
+    /// `AccessAdapter::drain_actions` returned, translated from an
+    /// AccessKit `ActionRequest` that named an exact node.
+    ///
+    /// The tree is still walked in full (there's no per-subtree id index to
+    /// prune the walk with), but `EventCtx::is_target` lets each widget
+    /// along the way tell whether it's the one the action was actually
+    /// aimed at, so e.g. two sibling checkboxes don't both toggle when only
+    /// one of them was clicked.
+    pub fn do_targeted_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        target: WidgetId,
+        event: &Event,
+        data: &mut T,
+        env: &Env,
+    ) {
+        ctx.target = Some(target);
+        self.root.event(ctx, event, data, env);
+        ctx.target = None;
+
+        if let Some(mut op) = ctx.pending_operation.take() {
+            self.root.apply_operation(op.as_mut(), self.focused_widget);
+            op.finish(ctx);
+        }
+
+        if let Some(id) = ctx.request_focus.take() {
+            self.focused_widget = Some(id);
+        }
+    }
+
+    /// Run the accessibility pass over the whole tree and return the
+    /// resulting `TreeUpdate`, rooted at whatever node(s) the root widget's
+    /// own `accessibility` call pushed.
+    ///
+    /// This is what a shell's `AccessAdapter::new`/`request_update` build-tree
+    /// closure (see `druid-shell`) is expected to call: it's the one place
+    /// that has both a `WidgetPod` to start the walk from and the
+    /// `focused_widget` needed to fill in `TreeUpdate::focus`.
+    pub fn build_access_tree(&mut self, window: &WindowHandle, data: &T, env: &Env) -> TreeUpdate {
+        let mut update = TreeUpdate {
+            nodes: Vec::new(),
+            tree: None,
+            focus: self.root.id().to_access_id(),
+        };
+        let mut ctx = AccessCtx {
+            window,
+            widget_id: self.root.id(),
+            bounds: self.root.layout_rect(),
+            update: &mut update,
+        };
+        if let Some(root_id) = self.root.accessibility(&mut ctx, data, env).into_iter().next() {
+            update.tree = Some(Tree::new(root_id));
+            if let Some(focused) = self.focused_widget {
+                update.focus = focused.to_access_id();
+            } else {
+                update.focus = root_id;
+            }
+        }
+        update
+    }
+}